@@ -0,0 +1,15 @@
+/// # Key Case
+/// Controls how [crate::EnvironmentVariable::create_env_string] joins and cases the words
+/// that make up a generated key, so `.env` files using something other than
+/// `SCREAMING_SNAKE_CASE` can still be matched.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum KeyCase {
+    /// `SCREAMING_SNAKE_CASE`, the default
+    ScreamingSnake,
+    /// `snake_case`
+    Snake,
+    /// `kebab-case`
+    Kebab,
+    /// Words joined as-is, without a separator or case change
+    AsIs,
+}
@@ -3,6 +3,12 @@ use std::fmt::Debug;
 use std::str::FromStr;
 use strum::IntoEnumIterator;
 
+pub mod env_errors;
+pub mod key_case;
+
+use env_errors::EnvEnumResult;
+use key_case::KeyCase;
+
 /// # EnvironmentVariable
 /// This trait is a link between the dotenv and your enums.
 /// The macro env_enum simplifies significantly its creation and its safety.
@@ -135,6 +141,48 @@ pub trait EnvironmentVariable
         }
     }
 
+    /// Get the value from the .env related to the enum value, or `None` when the key is absent
+    /// ```
+    /// use dotenv_enum::{env_enum, EnvironmentVariable};
+    /// use strum::IntoEnumIterator;
+    ///
+    /// dotenv::dotenv().ok();
+    /// env_enum!(TheEnumNameEnv, enum_test_module, [ValueOne, ValueTwo]);
+    ///
+    /// // Assuming the line:
+    /// //      THE_ENUM_NAME_VALUE_ONE = "val"
+    /// // exist in the .env
+    /// assert_eq!(TheEnumNameEnv::ValueOne.get_optional_value(), Some("val".to_string()));
+    ///
+    /// // Assuming the key THE_ENUM_NAME_VALUE_TWO does not exist in .env
+    /// assert_eq!(TheEnumNameEnv::ValueTwo.get_optional_value(), None);
+    /// ```
+    fn get_optional_value(&self) -> Option<String> {
+        env::var(self.get_key()).ok()
+    }
+
+    /// Get the value from the .env related to the enum value casted into the type T, or `None`
+    /// when the key is absent or the value cannot be parsed
+    /// ```
+    /// use dotenv_enum::{env_enum, EnvironmentVariable};
+    /// use strum::IntoEnumIterator;
+    ///
+    /// dotenv::dotenv().ok();
+    /// env_enum!(TheEnumNameEnv, enum_test_module, [ValueOne, ValueTwo]);
+    ///
+    /// // Assuming the line:
+    /// //      THE_ENUM_NAME_VALUE_ONE = "val"
+    /// // exist in the .env
+    /// assert_eq!(TheEnumNameEnv::ValueOne.get_optional_casted_value::<String>(), Some("val".to_string()));
+    /// assert_eq!(TheEnumNameEnv::ValueOne.get_optional_casted_value::<u32>(), None);
+    ///
+    /// // Assuming the key THE_ENUM_NAME_VALUE_TWO does not exist in .env
+    /// assert_eq!(TheEnumNameEnv::ValueTwo.get_optional_casted_value::<String>(), None);
+    /// ```
+    fn get_optional_casted_value<T: FromStr>(&self) -> Option<T> {
+        self.get_optional_value()?.parse::<T>().ok()
+    }
+
     /// Get the value from the .env related to the enum value and unwrap it
     /// This function will panic instead of sending an Err
     /// ```
@@ -153,6 +201,44 @@ pub trait EnvironmentVariable
         self.get_value().unwrap_or_else(|message| { panic!("{}", message) })
     }
 
+    /// The inline default declared for this variant through the `env_enum!` macro. Defaults to
+    /// `None` when the variant declares no default. [EnvironmentVariable::get_value_or_default]
+    /// does not read it: its fallback is always the `default` argument it is given
+    /// ```
+    /// use dotenv_enum::{env_enum, EnvironmentVariable};
+    /// use strum::IntoEnumIterator;
+    ///
+    /// env_enum!(TheEnumNameEnv, enum_test_module, [ValueOne, ValueTwo]);
+    ///
+    /// assert_eq!(TheEnumNameEnv::ValueOne.default_for(), None);
+    /// ```
+    fn default_for(&self) -> Option<String> {
+        None
+    }
+
+    /// Get the value from the .env related to the enum value, falling back to `default` when the
+    /// key is absent. The default is written back with `env::set_var` so later reads stay
+    /// consistent
+    /// ```
+    /// use dotenv_enum::{env_enum, EnvironmentVariable};
+    /// use strum::IntoEnumIterator;
+    ///
+    /// env_enum!(TheEnumNameEnv, enum_test_module, [ValueOne, ValueTwo]);
+    ///
+    /// // Assuming the key THE_ENUM_NAME_VALUE_TWO does not exist in .env
+    /// assert_eq!(TheEnumNameEnv::ValueTwo.get_value_or_default("1920"), "1920".to_string());
+    /// assert_eq!(TheEnumNameEnv::ValueTwo.get_value(), Ok("1920".to_string()));
+    /// ```
+    fn get_value_or_default(&self, default: &str) -> String {
+        match self.get_value() {
+            Ok(var) => var,
+            Err(_) => {
+                env::set_var(self.get_key(), default);
+                default.to_string()
+            }
+        }
+    }
+
     /// Get the value from the .env related to the enum value and casted it into the type T
     /// ```
     /// use dotenv_enum::{env_enum, EnvironmentVariable};
@@ -201,7 +287,176 @@ pub trait EnvironmentVariable
         self.get_casted_value::<T>().unwrap_or_else(|message| panic!("{}", message))
     }
 
-    /// Create a full capitalize, seperated by underscored, without suffix Env, and merge name_value
+    /// Get the value from the .env related to the enum value and casted it into the type T,
+    /// distinguishing a missing key (`Absent`) from a present but unparsable one (`IncorrectCast`)
+    /// ```
+    /// use dotenv_enum::{env_enum, EnvironmentVariable};
+    /// use dotenv_enum::env_errors::EnvEnumResult;
+    /// use strum::IntoEnumIterator;
+    ///
+    /// dotenv::dotenv().ok();
+    /// env_enum!(TheEnumNameEnv, enum_test_module, [ValueOne, ValueTwo]);
+    ///
+    /// // Assuming the line:
+    /// //      THE_ENUM_NAME_VALUE_ONE = "val"
+    /// // exist in the .env
+    /// assert_eq!(TheEnumNameEnv::ValueOne.get_result::<String>(), EnvEnumResult::Ok("val".to_string()));
+    /// assert_eq!(TheEnumNameEnv::ValueOne.get_result::<u32>(), EnvEnumResult::IncorrectCast("Cannot cast THE_ENUM_NAME_VALUE_ONE into u32".to_string()));
+    ///
+    /// // Assuming the key THE_ENUM_NAME_VALUE_TWO does not exist in .env
+    /// assert_eq!(TheEnumNameEnv::ValueTwo.get_result::<String>(), EnvEnumResult::Absent("No THE_ENUM_NAME_VALUE_TWO in .env file".to_string()));
+    /// ```
+    fn get_result<T: Clone + FromStr>(&self) -> EnvEnumResult<T>
+        where <T as FromStr>::Err: Debug {
+        match self.get_value() {
+            Ok(var) => match var.parse::<T>() {
+                Ok(val) => EnvEnumResult::Ok(val),
+                Err(_) => EnvEnumResult::IncorrectCast(format!(
+                    "Cannot cast {} into {}",
+                    self.get_key(),
+                    std::any::type_name::<T>()
+                )),
+            },
+            Err(message) => EnvEnumResult::Absent(message),
+        }
+    }
+
+    /// Same as [EnvironmentVariable::get_result] but substitutes `default` when the key is Absent,
+    /// leaving IncorrectCast untouched so a malformed value still surfaces as an error
+    /// ```
+    /// use dotenv_enum::{env_enum, EnvironmentVariable};
+    /// use dotenv_enum::env_errors::EnvEnumResult;
+    /// use strum::IntoEnumIterator;
+    ///
+    /// dotenv::dotenv().ok();
+    /// env_enum!(TheEnumNameEnv, enum_test_module, [ValueOne, ValueTwo]);
+    ///
+    /// // Assuming the key THE_ENUM_NAME_VALUE_TWO does not exist in .env
+    /// assert_eq!(TheEnumNameEnv::ValueTwo.get_result_or("default".to_string()), EnvEnumResult::Ok("default".to_string()));
+    /// ```
+    fn get_result_or<T: Clone + FromStr>(&self, default: T) -> EnvEnumResult<T>
+        where <T as FromStr>::Err: Debug {
+        match self.get_result::<T>() {
+            EnvEnumResult::Absent(_) => EnvEnumResult::Ok(default),
+            other => other,
+        }
+    }
+
+    /// Split `raw` on `delimiter` and cast every trimmed piece into the type T, naming `key`
+    /// and the offending element index in the error
+    fn parse_vec_elements<T: FromStr>(key: &str, raw: &str, delimiter: &str) -> Result<Vec<T>, String>
+        where <T as FromStr>::Err: Debug {
+        raw.split(delimiter)
+            .enumerate()
+            .map(|(index, piece)| piece.trim().parse::<T>().map_err(|_| format!(
+                "Cannot cast element {} of {} into {}",
+                index,
+                key,
+                std::any::type_name::<T>()
+            )))
+            .collect()
+    }
+
+    /// Get the value from the .env related to the enum value, split it on `delimiter`
+    /// and cast every trimmed piece into the type T
+    /// ```
+    /// use dotenv_enum::{env_enum, EnvironmentVariable};
+    /// use strum::IntoEnumIterator;
+    ///
+    /// dotenv::dotenv().ok();
+    /// env_enum!(NetworkEnv, enum_test_module, [Ports]);
+    ///
+    /// // Assuming the line:
+    /// //      NETWORK_PORTS = "80, 443, 8080"
+    /// // exist in the .env
+    /// assert_eq!(NetworkEnv::Ports.get_vec_value::<u16>(","), Ok(vec![80, 443, 8080]));
+    /// ```
+    fn get_vec_value<T: FromStr>(&self, delimiter: &str) -> Result<Vec<T>, String>
+        where <T as FromStr>::Err: Debug {
+        Self::parse_vec_elements(&self.get_key(), &self.get_value()?, delimiter)
+    }
+
+    /// Same as [EnvironmentVariable::get_vec_value] but splitting on the default `,` delimiter
+    /// ```
+    /// use dotenv_enum::{env_enum, EnvironmentVariable};
+    /// use strum::IntoEnumIterator;
+    ///
+    /// dotenv::dotenv().ok();
+    /// env_enum!(NetworkEnv, enum_test_module, [Ports]);
+    ///
+    /// // Assuming the line:
+    /// //      NETWORK_PORTS = "80,443,8080"
+    /// // exist in the .env
+    /// assert_eq!(NetworkEnv::Ports.get_vec_value_default::<u16>(), Ok(vec![80, 443, 8080]));
+    /// ```
+    fn get_vec_value_default<T: FromStr>(&self) -> Result<Vec<T>, String>
+        where <T as FromStr>::Err: Debug {
+        self.get_vec_value(",")
+    }
+
+    /// Same as [EnvironmentVariable::get_vec_value] but returning an [EnvEnumResult]
+    /// so a missing key is distinguishable from an unparsable element
+    /// ```
+    /// use dotenv_enum::{env_enum, EnvironmentVariable};
+    /// use dotenv_enum::env_errors::EnvEnumResult;
+    /// use strum::IntoEnumIterator;
+    ///
+    /// dotenv::dotenv().ok();
+    /// env_enum!(NetworkEnv, enum_test_module, [Ports]);
+    ///
+    /// // Assuming the line:
+    /// //      NETWORK_PORTS = "80, 443, 8080"
+    /// // exist in the .env
+    /// assert_eq!(NetworkEnv::Ports.get_vec_result::<u16>(","), EnvEnumResult::Ok(vec![80, 443, 8080]));
+    /// ```
+    fn get_vec_result<T: Clone + FromStr>(&self, delimiter: &str) -> EnvEnumResult<Vec<T>>
+        where <T as FromStr>::Err: Debug {
+        match self.get_value() {
+            Ok(raw) => match Self::parse_vec_elements(&self.get_key(), &raw, delimiter) {
+                Ok(values) => EnvEnumResult::Ok(values),
+                Err(message) => EnvEnumResult::IncorrectCast(message),
+            },
+            Err(message) => EnvEnumResult::Absent(message),
+        }
+    }
+
+    /// Same as [EnvironmentVariable::get_vec_result] but splitting on the default `,` delimiter
+    /// ```
+    /// use dotenv_enum::{env_enum, EnvironmentVariable};
+    /// use dotenv_enum::env_errors::EnvEnumResult;
+    /// use strum::IntoEnumIterator;
+    ///
+    /// dotenv::dotenv().ok();
+    /// env_enum!(NetworkEnv, enum_test_module, [Ports]);
+    ///
+    /// // Assuming the line:
+    /// //      NETWORK_PORTS = "80,443,8080"
+    /// // exist in the .env
+    /// assert_eq!(NetworkEnv::Ports.get_vec_result_default::<u16>(), EnvEnumResult::Ok(vec![80, 443, 8080]));
+    /// ```
+    fn get_vec_result_default<T: Clone + FromStr>(&self) -> EnvEnumResult<Vec<T>>
+        where <T as FromStr>::Err: Debug {
+        self.get_vec_result(",")
+    }
+
+    /// The casing strategy used by [EnvironmentVariable::create_env_string] to join the words
+    /// of a generated key. Defaults to [KeyCase::ScreamingSnake]; the `env_enum!` macro can
+    /// override it per enum
+    /// ```
+    /// use dotenv_enum::{env_enum, EnvironmentVariable};
+    /// use dotenv_enum::key_case::KeyCase;
+    /// use strum::IntoEnumIterator;
+    ///
+    /// env_enum!(TheEnumNameEnv, enum_test_module, [Value]);
+    ///
+    /// assert_eq!(TheEnumNameEnv::key_case(), KeyCase::ScreamingSnake);
+    /// ```
+    fn key_case() -> KeyCase {
+        KeyCase::ScreamingSnake
+    }
+
+    /// Create a key by joining the words of the enum name (without its `Env` suffix) and the
+    /// variant name, cased according to [EnvironmentVariable::key_case]
     /// ```
     /// use dotenv_enum::{env_enum, EnvironmentVariable};
     /// use strum::IntoEnumIterator;
@@ -217,7 +472,26 @@ pub trait EnvironmentVariable
         if name[name_size].eq("Env") {
             name.remove(name_size);
         }
-        format!("{}_{}", name.join("_").to_uppercase(), values.join("_").to_uppercase())
+        name.extend(values);
+        Self::apply_key_case(name)
+    }
+
+    /// Join and case a vector of words according to [EnvironmentVariable::key_case]
+    /// ```
+    /// use dotenv_enum::{env_enum, EnvironmentVariable};
+    /// use strum::IntoEnumIterator;
+    ///
+    /// env_enum!(TheEnumNameEnv, enum_test_module, [Value]);
+    ///
+    /// assert_eq!(TheEnumNameEnv::apply_key_case(vec!["Lol".to_string(), "A".to_string(), "Value".to_string()]), "LOL_A_VALUE".to_string())
+    /// ```
+    fn apply_key_case(words: Vec<String>) -> String {
+        match Self::key_case() {
+            KeyCase::ScreamingSnake => words.iter().map(|word| word.to_uppercase()).collect::<Vec<_>>().join("_"),
+            KeyCase::Snake => words.iter().map(|word| word.to_lowercase()).collect::<Vec<_>>().join("_"),
+            KeyCase::Kebab => words.iter().map(|word| word.to_lowercase()).collect::<Vec<_>>().join("-"),
+            KeyCase::AsIs => words.join(""),
+        }
     }
 
     /// Create a vector of all the words seperated by either underscores or capital letters
@@ -270,9 +544,70 @@ pub trait EnvironmentVariable
 ///
 /// env_enum!(TheEnumNameEnv, enum_test_module, [Value]);
 /// ```
+///
+/// A variant can override its derived key with a literal, for legacy or non-conforming names
+/// ```
+/// use dotenv_enum::{env_enum, EnvironmentVariable};
+/// use strum::IntoEnumIterator;
+///
+/// env_enum!(DbEnv, db_test, [Host, Port = "DATABASE_URL_PORT"]);
+///
+/// assert_eq!(DbEnv::Port.get_key(), "DATABASE_URL_PORT".to_string());
+/// ```
+///
+/// A trailing [key_case::KeyCase] switches the casing strategy used for derived keys
+/// ```
+/// use dotenv_enum::{env_enum, EnvironmentVariable};
+/// use dotenv_enum::key_case::KeyCase;
+/// use strum::IntoEnumIterator;
+///
+/// env_enum!(SettingsEnv, settings_test, [ResolutionWidth, ResolutionHeight], KeyCase::Kebab);
+///
+/// assert_eq!(SettingsEnv::ResolutionWidth.get_key(), "settings-resolution-width".to_string());
+/// ```
+///
+/// A variant can declare an inline default, so a missing key falls back to it instead of the
+/// generated tests requiring it to exist in the .env
+/// ```
+/// use dotenv_enum::{env_enum, EnvironmentVariable};
+/// use strum::IntoEnumIterator;
+///
+/// env_enum!(SettingsEnv, settings_test, [ResolutionWidth => "1920", ResolutionHeight => "1080"]);
+///
+/// assert_eq!(SettingsEnv::ResolutionWidth.get_value_or_default(&SettingsEnv::ResolutionWidth.default_for().unwrap()), "1920".to_string());
+/// ```
 #[macro_export]
 macro_rules! env_enum {
-    ($enum_name: ident, $env_test_name: ident, [$($var_name: ident), *]) => {
+    (@key $enum_name: ident, $var_name: ident) => {
+        Self::create_env_string(stringify!($enum_name), stringify!($var_name))
+    };
+    (@key $enum_name: ident, $var_name: ident, $key_override: literal) => {
+        $key_override.to_string()
+    };
+    (@default) => {
+        None
+    };
+    (@default $default: literal) => {
+        Some($default.to_string())
+    };
+    (@test $enum_name: ident, $var_name: ident) => {
+        #[test]
+        fn $var_name() {
+            dotenv::dotenv().ok();
+            assert!(!my_crate::$enum_name::$var_name.unwrap_value().is_empty());
+        }
+    };
+    (@test $enum_name: ident, $var_name: ident, $default: literal) => {
+        #[test]
+        fn $var_name() {
+            dotenv::dotenv().ok();
+            assert!(!my_crate::$enum_name::$var_name.get_value_or_default($default).is_empty());
+        }
+    };
+    ($enum_name: ident, $env_test_name: ident, [$($var_name: ident $(= $key_override: literal)? $(=> $default: literal)?), *]) => {
+        $crate::env_enum!($enum_name, $env_test_name, [$($var_name $(= $key_override)? $(=> $default)?), *], $crate::key_case::KeyCase::ScreamingSnake);
+    };
+    ($enum_name: ident, $env_test_name: ident, [$($var_name: ident $(= $key_override: literal)? $(=> $default: literal)?), *], $key_case: expr) => {
         #[derive(Copy, Clone, strum_macros::EnumIter, PartialEq, Debug)]
         pub enum $enum_name {
             $($var_name,)*
@@ -281,7 +616,17 @@ macro_rules! env_enum {
         impl EnvironmentVariable for $enum_name {
             fn get_key(&self) -> String {
                 match self {
-                    $($enum_name::$var_name => Self::create_env_string(stringify!($enum_name), stringify!($var_name)),)*
+                    $($enum_name::$var_name => $crate::env_enum!(@key $enum_name, $var_name $(, $key_override)?),)*
+                }
+            }
+
+            fn key_case() -> $crate::key_case::KeyCase {
+                $key_case
+            }
+
+            fn default_for(&self) -> Option<String> {
+                match self {
+                    $($enum_name::$var_name => $crate::env_enum!(@default $($default)?),)*
                 }
             }
         }
@@ -297,11 +642,7 @@ macro_rules! env_enum {
                 extern crate self as my_crate;
                 use dotenv_enum::EnvironmentVariable;
 
-                $(#[test]
-                fn $var_name() {
-                    dotenv::dotenv().ok();
-                    assert!(!my_crate::$enum_name::$var_name.unwrap_value().is_empty());
-                })*
+                $($crate::env_enum!(@test $enum_name, $var_name $(, $default)?);)*
             }
 
             #[test]